@@ -90,10 +90,120 @@
 //!     let _ = run();
 //! }
 //! ```
+//!
+//! # `no_std`
+//!
+//! This crate only needs `alloc`. Disable the default `std` feature
+//! to build it as `no_std`; the only thing that feature adds is the
+//! `std::error::Error` impl on `MultiError`.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(not(feature = "std"))]
+#[macro_use]
+extern crate alloc;
 
+#[cfg(feature = "std")]
 use std::error::Error as StdError;
-use std::fmt;
-use std::vec;
+use core::fmt;
+use core::ops::ControlFlow;
+#[cfg(feature = "std")]
+use std::vec::{self, Vec};
+#[cfg(not(feature = "std"))]
+use alloc::vec::{self, Vec};
+
+/// A strategy for accumulating the errors produced while draining a
+/// `Result` iterator.
+///
+/// `push_err` is called with each `Err` as it's encountered, and
+/// decides whether the drain should keep going (`ControlFlow::Continue`)
+/// or stop immediately (`ControlFlow::Break`). Once the iterator is
+/// exhausted (or the collector breaks it early), `finish` turns
+/// whatever the collector accumulated into the final result.
+pub trait ErrorCollector<E> {
+    /// The value produced if any error was collected.
+    type Output;
+
+    /// Record an error, returning whether iteration should continue.
+    fn push_err(&mut self, e: E) -> ControlFlow<()>;
+
+    /// Consume the collector, producing the final result.
+    fn finish(self) -> Result<(), Self::Output>;
+}
+
+/// An `ErrorCollector` that stops at the first error, reproducing
+/// the behavior of `fail_fast_if_err`.
+pub struct FirstError<E>(Option<E>);
+
+impl<E> FirstError<E> {
+    pub fn new() -> FirstError<E> { FirstError(None) }
+}
+
+impl<E> Default for FirstError<E> {
+    fn default() -> Self { FirstError(None) }
+}
+
+impl<E> ErrorCollector<E> for FirstError<E> {
+    type Output = E;
+
+    fn push_err(&mut self, e: E) -> ControlFlow<()> {
+        self.0 = Some(e);
+        ControlFlow::Break(())
+    }
+
+    fn finish(self) -> Result<(), E> {
+        match self.0 {
+            Some(e) => Err(e),
+            None => Ok(()),
+        }
+    }
+}
+
+/// An `ErrorCollector` that keeps going and accumulates every error,
+/// reproducing the behavior of `fail_slow_if_err`.
+pub struct AllErrors<E>(Vec<E>);
+
+impl<E> AllErrors<E> {
+    pub fn new() -> AllErrors<E> { AllErrors(vec![]) }
+}
+
+impl<E> Default for AllErrors<E> {
+    fn default() -> Self { AllErrors(vec![]) }
+}
+
+impl<E> ErrorCollector<E> for AllErrors<E> {
+    type Output = MultiError<E>;
+
+    fn push_err(&mut self, e: E) -> ControlFlow<()> {
+        self.0.push(e);
+        ControlFlow::Continue(())
+    }
+
+    fn finish(self) -> Result<(), MultiError<E>> {
+        if self.0.is_empty() {
+            Ok(())
+        } else {
+            Err(MultiError::new(self.0))
+        }
+    }
+}
+
+/// An `ErrorCollector` that drops every error and never fails, so
+/// only the `Ok` values survive.
+#[derive(Default)]
+pub struct Ignore;
+
+impl<E> ErrorCollector<E> for Ignore {
+    type Output = E;
+
+    fn push_err(&mut self, _e: E) -> ControlFlow<()> {
+        ControlFlow::Continue(())
+    }
+
+    fn finish(self) -> Result<(), E> {
+        Ok(())
+    }
+}
 
 pub trait ResultIterExt<T, E>: Sized + Iterator<Item = Result<T, E>> {
     fn end_if_err(self) -> EndIfErrIter<T, E, Self>;
@@ -103,6 +213,87 @@ pub trait ResultIterExt<T, E>: Sized + Iterator<Item = Result<T, E>> {
         self.end_if_err().fail_slow_if_err()
             .map_err(|e| e.into_iter().next().expect(""))
     }
+
+    /// Drain the iterator, routing every `Err` through `collector` and
+    /// buffering every `Ok`, stopping early if the collector asks to.
+    ///
+    /// This generalizes `fail_fast_if_err`/`fail_slow_if_err`/`end_if_err`
+    /// into a single entry point parameterized over an `ErrorCollector`,
+    /// so callers can plug in their own error-accumulation policy.
+    fn collect_results<C>(self, mut collector: C) -> Result<vec::IntoIter<T>, C::Output>
+        where C: ErrorCollector<E>
+    {
+        let mut goodies = vec![];
+
+        for el in self {
+            match el {
+                Ok(a) => goodies.push(a),
+                Err(e) => {
+                    if let ControlFlow::Break(()) = collector.push_err(e) {
+                        break;
+                    }
+                }
+            }
+        }
+
+        collector.finish().map(|()| goodies.into_iter())
+    }
+
+    /// Wrap this iterator in a lazy adapter that yields the inner `Ok`
+    /// values directly and stops at the first `Err`, without buffering
+    /// the source or allocating any intermediate storage.
+    ///
+    /// The error, if any, is retrieved afterwards via
+    /// `Fallible::into_result`.
+    fn process_results(self) -> Fallible<Self, E> {
+        Fallible { iter: self, err: None }
+    }
+
+    /// Drain the iterator, collecting *every* `Ok` and *every* `Err`
+    /// regardless of order, so no successful value is lost just
+    /// because an error occurred earlier.
+    ///
+    /// Unlike `fail_slow_if_err`, which stops accumulating `Ok`
+    /// values once the first `Err` is seen, this keeps both sides
+    /// complete, for callers who want to process the good data and
+    /// separately report on the bad.
+    fn partition_results(self) -> (Vec<T>, Vec<E>) {
+        let mut goodies = vec![];
+        let mut baddies = vec![];
+
+        for el in self {
+            match el {
+                Ok(a) => goodies.push(a),
+                Err(b) => baddies.push(b),
+            }
+        }
+
+        (goodies, baddies)
+    }
+
+    /// Wrap this iterator in a lazy adapter that yields every `Ok`
+    /// value and silently skips every `Err`, with no error type
+    /// threaded through to the caller.
+    fn ok_only(self) -> OkOnlyIter<T, E, Self> {
+        OkOnlyIter(self)
+    }
+
+    /// Wrap this iterator in a lazy adapter that yields `Ok` values
+    /// and halts at the first `Err`, discarding it rather than
+    /// surfacing it to the caller.
+    fn ok_until_err(self) -> OkUntilErrIter<T, E, Self> {
+        OkUntilErrIter(self, State::Continue)
+    }
+
+    /// Wrap this iterator of `Result<C, E>`, where `C` is itself
+    /// iterable, into an iterator of `Result<U, E>`: every `Ok(c)` is
+    /// flattened into its individual `Ok(u)` elements, and every
+    /// `Err(e)` passes through unchanged as a single element.
+    fn flatten_results<U>(self) -> FlattenResults<Self, T, E>
+        where T: IntoIterator<Item = U>
+    {
+        FlattenResults { outer: self, inner: None }
+    }
 }
 
 impl<T, E, I> ResultIterExt<T, E> for I
@@ -173,9 +364,140 @@ impl<T, E, I> Iterator for EndIfErrIter<T, E, I>
     }
 }
 
+/// A lazy adapter, produced by [`ok_only`](ResultIterExt::ok_only),
+/// that yields every `Ok` value and skips every `Err`.
+pub struct OkOnlyIter<T, E, I>(I)
+    where I: Iterator<Item = Result<T, E>>;
+
+impl<T, E, I> Iterator for OkOnlyIter<T, E, I>
+    where I: Iterator<Item = Result<T, E>>
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        loop {
+            match self.0.next() {
+                Some(Ok(t)) => return Some(t),
+                Some(Err(_)) => continue,
+                None => return None,
+            }
+        }
+    }
+}
+
+/// A lazy adapter, produced by
+/// [`ok_until_err`](ResultIterExt::ok_until_err), that yields `Ok`
+/// values and stops at the first `Err`, discarding it.
+pub struct OkUntilErrIter<T, E, I>(I, State)
+    where I: Iterator<Item = Result<T, E>>;
+
+impl<T, E, I> Iterator for OkUntilErrIter<T, E, I>
+    where I: Iterator<Item = Result<T, E>>
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        match self.1 {
+            State::Continue => {
+                match self.0.next() {
+                    Some(Ok(t)) => Some(t),
+                    Some(Err(_)) | None => {
+                        self.1 = State::End;
+                        None
+                    }
+                }
+            }
+            State::End => None,
+        }
+    }
+}
+
+/// A lazy adapter, produced by
+/// [`flatten_results`](ResultIterExt::flatten_results), that flattens
+/// each `Ok` collection into its individual elements while passing
+/// `Err`s through unchanged.
+pub struct FlattenResults<I, T, E>
+    where I: Iterator<Item = Result<T, E>>,
+          T: IntoIterator
+{
+    outer: I,
+    inner: Option<T::IntoIter>,
+}
+
+impl<I, T, E, U> Iterator for FlattenResults<I, T, E>
+    where I: Iterator<Item = Result<T, E>>,
+          T: IntoIterator<Item = U>
+{
+    type Item = Result<U, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(inner) = self.inner.as_mut() {
+                if let Some(u) = inner.next() {
+                    return Some(Ok(u));
+                }
+                self.inner = None;
+            }
+
+            match self.outer.next() {
+                Some(Ok(c)) => self.inner = Some(c.into_iter()),
+                Some(Err(e)) => return Some(Err(e)),
+                None => return None,
+            }
+        }
+    }
+}
+
+/// A lazy, zero-buffering adapter produced by
+/// [`process_results`](ResultIterExt::process_results).
+///
+/// Iterating yields the inner `Ok(T)` of each element directly; the
+/// first `Err(E)` ends the iterator and is stashed away to be
+/// retrieved with `into_result`.
+pub struct Fallible<I, E> {
+    iter: I,
+    err: Option<E>,
+}
+
+impl<T, E, I> Iterator for Fallible<I, E>
+    where I: Iterator<Item = Result<T, E>>
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        if self.err.is_some() {
+            return None;
+        }
+
+        match self.iter.next() {
+            Some(Ok(t)) => Some(t),
+            Some(Err(e)) => {
+                self.err = Some(e);
+                None
+            }
+            None => None,
+        }
+    }
+}
+
+impl<I, E> Fallible<I, E> {
+    /// Retrieve the error that ended iteration, if any.
+    ///
+    /// This should be called after the adapter has been fully
+    /// iterated; calling it earlier will report `Ok(())` even if
+    /// the source has further errors still to be reached.
+    pub fn into_result(self) -> Result<(), E> {
+        match self.err {
+            Some(e) => Err(e),
+            None => Ok(()),
+        }
+    }
+}
+
 #[derive(Debug)]
 pub struct MultiError<E>(Vec<E>);
 
+#[cfg(feature = "std")]
 impl<E> StdError for MultiError<E>
     where E: StdError
 {
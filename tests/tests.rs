@@ -1,5 +1,5 @@
 extern crate result_iter;
-use result_iter::{ResultIterExt, MultiError};
+use result_iter::{ResultIterExt, MultiError, ErrorCollector, FirstError, AllErrors, Ignore};
 
 use std::io;
 
@@ -48,3 +48,188 @@ fn smoke() {
 
     assert!(r.is_err());
 }
+
+#[test]
+fn collect_results_first_error() {
+    let err = || Err(io::Error::from(io::ErrorKind::Other));
+    let nerrs: Vec<Result<i32, io::Error>> = vec![Ok(1), Ok(2), Ok(3)];
+    let errs = vec![Ok(1), err(), err(), Ok(2)];
+
+    let r = nerrs.into_iter().collect_results(FirstError::new())
+        .unwrap().collect::<Vec<_>>();
+    assert_eq!(r, vec![1, 2, 3]);
+
+    let e = errs.into_iter().collect_results(FirstError::new())
+        .unwrap_err();
+    assert!(e.kind() == io::ErrorKind::Other);
+}
+
+#[test]
+fn collect_results_all_errors() {
+    let err = || Err(io::Error::from(io::ErrorKind::Other));
+    let errs = vec![Ok(1), err(), err(), Ok(2)];
+
+    let e = errs.into_iter().collect_results(AllErrors::new())
+        .unwrap_err();
+    assert!(e.len() == 2);
+}
+
+#[test]
+fn collect_results_ignore() {
+    let err = || Err(io::Error::from(io::ErrorKind::Other));
+    let errs = vec![Ok(1), err(), err(), Ok(2)];
+
+    let r = errs.into_iter().collect_results(Ignore)
+        .unwrap().collect::<Vec<_>>();
+    assert_eq!(r, vec![1, 2]);
+}
+
+#[test]
+fn collect_results_default_collectors() {
+    // io::Error isn't Default, so this only compiles if FirstError's
+    // and AllErrors's Default impls don't require E: Default.
+    let err = || Err(io::Error::from(io::ErrorKind::Other));
+    let errs = || vec![Ok(1), err(), err(), Ok(2)];
+
+    let e = errs().into_iter().collect_results(FirstError::default())
+        .unwrap_err();
+    assert!(e.kind() == io::ErrorKind::Other);
+
+    let e = errs().into_iter().collect_results(AllErrors::default())
+        .unwrap_err();
+    assert!(e.len() == 2);
+}
+
+#[test]
+fn collect_results_custom_collector() {
+    // A collector that caps at one error, then stops.
+    struct CapAtOne(Vec<io::Error>);
+
+    impl ErrorCollector<io::Error> for CapAtOne {
+        type Output = MultiError<io::Error>;
+
+        fn push_err(&mut self, e: io::Error) -> std::ops::ControlFlow<()> {
+            self.0.push(e);
+            std::ops::ControlFlow::Break(())
+        }
+
+        fn finish(self) -> Result<(), MultiError<io::Error>> {
+            if self.0.is_empty() {
+                Ok(())
+            } else {
+                Err(MultiError::new(self.0))
+            }
+        }
+    }
+
+    let err = || Err(io::Error::from(io::ErrorKind::Other));
+    let errs = vec![Ok(1), err(), err(), Ok(2)];
+
+    let e = errs.into_iter().collect_results(CapAtOne(vec![]))
+        .unwrap_err();
+    assert!(e.len() == 1);
+}
+
+#[test]
+fn process_results_success() {
+    let nerrs: Vec<Result<i32, io::Error>> = vec![Ok(1), Ok(2), Ok(3)];
+
+    let mut it = nerrs.into_iter().process_results();
+    let r = (&mut it).collect::<Vec<_>>();
+    assert_eq!(r, vec![1, 2, 3]);
+    assert!(it.into_result().is_ok());
+}
+
+#[test]
+fn process_results_stops_at_first_err_and_stays_halted() {
+    let err = || Err(io::Error::from(io::ErrorKind::Other));
+    let errs = vec![Ok(1), Ok(2), err(), Ok(3), Ok(4)];
+
+    let mut it = errs.into_iter().process_results();
+
+    assert_eq!(it.next(), Some(1));
+    assert_eq!(it.next(), Some(2));
+    assert_eq!(it.next(), None);
+
+    // The adapter is fused: it must keep reporting exhausted even
+    // though the wrapped iterator still has values past the error.
+    assert_eq!(it.next(), None);
+    assert_eq!(it.next(), None);
+
+    let e = it.into_result().unwrap_err();
+    assert!(e.kind() == io::ErrorKind::Other);
+}
+
+#[test]
+fn partition_results_keeps_all_oks_and_all_errs() {
+    let err = || Err(io::Error::from(io::ErrorKind::Other));
+    let errs = vec![Ok(1), err(), Ok(2), err(), Ok(3)];
+
+    let (goodies, baddies) = errs.into_iter().partition_results();
+
+    // Unlike fail_slow_if_err, every Ok is kept, even ones after
+    // the first Err.
+    assert_eq!(goodies, vec![1, 2, 3]);
+    assert!(baddies.len() == 2);
+}
+
+#[test]
+fn partition_results_all_ok() {
+    let nerrs: Vec<Result<i32, io::Error>> = vec![Ok(1), Ok(2), Ok(3)];
+
+    let (goodies, baddies) = nerrs.into_iter().partition_results();
+
+    assert_eq!(goodies, vec![1, 2, 3]);
+    assert!(baddies.is_empty());
+}
+
+#[test]
+fn ok_only_skips_errors_and_keeps_going() {
+    let err = || Err(io::Error::from(io::ErrorKind::Other));
+    let errs = vec![Ok(1), err(), Ok(2), err(), Ok(3)];
+
+    let r = errs.into_iter().ok_only().collect::<Vec<_>>();
+    assert_eq!(r, vec![1, 2, 3]);
+}
+
+#[test]
+fn ok_until_err_halts_at_first_error_and_stays_halted() {
+    let err = || Err(io::Error::from(io::ErrorKind::Other));
+    let errs = vec![Ok(1), Ok(2), err(), Ok(3), Ok(4)];
+
+    let mut it = errs.into_iter().ok_until_err();
+
+    assert_eq!(it.next(), Some(1));
+    assert_eq!(it.next(), Some(2));
+    assert_eq!(it.next(), None);
+
+    // Must stay halted rather than resuming past the error.
+    assert_eq!(it.next(), None);
+    assert_eq!(it.next(), None);
+}
+
+#[test]
+fn flatten_results_flattens_oks_and_passes_through_errs() {
+    let err = || Err(io::Error::from(io::ErrorKind::Other));
+    let batches: Vec<Result<Vec<i32>, io::Error>> =
+        vec![Ok(vec![1, 2]), err(), Ok(vec![]), Ok(vec![3])];
+
+    let r = batches.into_iter().flatten_results().collect::<Vec<_>>();
+
+    assert_eq!(r.len(), 4);
+    assert_eq!(r[0].as_ref().unwrap(), &1);
+    assert_eq!(r[1].as_ref().unwrap(), &2);
+    assert!(r[2].is_err());
+    assert_eq!(r[3].as_ref().unwrap(), &3);
+}
+
+#[test]
+fn flatten_results_composes_with_fail_fast_if_err() {
+    let batches: Vec<Result<Vec<i32>, io::Error>> =
+        vec![Ok(vec![1, 2]), Ok(vec![3])];
+
+    let r = batches.into_iter().flatten_results()
+        .fail_fast_if_err().unwrap().collect::<Vec<_>>();
+
+    assert_eq!(r, vec![1, 2, 3]);
+}